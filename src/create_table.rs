@@ -0,0 +1,125 @@
+use itertools::Itertools;
+use std::fmt;
+
+use crate::{Column, ColumnSchema, Dialect, SchemaTable};
+
+/// Table-level constraint in a `CREATE TABLE` statement.
+pub enum TableConstraint<'i> {
+    PrimaryKey(Vec<Column<'i>>),
+    ForeignKey {
+        columns: Vec<Column<'i>>,
+        references: SchemaTable<'i>,
+        references_columns: Vec<Column<'i>>,
+    },
+}
+
+impl<'i> TableConstraint<'i> {
+    fn to_string_for<D: Dialect>(&self) -> String {
+        match self {
+            TableConstraint::PrimaryKey(columns) => format!(
+                "PRIMARY KEY ({})",
+                columns.iter().map(|c| c.quoted_for::<D>().to_string()).join(", ")
+            ),
+            TableConstraint::ForeignKey {
+                columns,
+                references,
+                references_columns,
+            } => format!(
+                "FOREIGN KEY ({}) REFERENCES {} ({})",
+                columns.iter().map(|c| c.quoted_for::<D>().to_string()).join(", "),
+                references.quoted_for::<D>(),
+                references_columns.iter().map(|c| c.quoted_for::<D>().to_string()).join(", ")
+            ),
+        }
+    }
+}
+
+/// Builds a complete, correctly quoted `CREATE TABLE` statement out of `ColumnSchema`
+/// columns and table-level constraints, for a given SQL `Dialect`.
+pub struct CreateTable<'i, D: Dialect> {
+    table: SchemaTable<'i>,
+    columns: Vec<ColumnSchema<'i, D>>,
+    constraints: Vec<TableConstraint<'i>>,
+}
+
+impl<'i, D: Dialect> CreateTable<'i, D> {
+    /// Create empty table builder for given table.
+    pub fn new(table: SchemaTable<'i>) -> Self {
+        CreateTable {
+            table,
+            columns: Vec::new(),
+            constraints: Vec::new(),
+        }
+    }
+
+    /// Appends a column with fluid API.
+    pub fn column(mut self, column: ColumnSchema<'i, D>) -> Self {
+        self.columns.push(column);
+        self
+    }
+
+    /// Appends all columns with fluid API.
+    pub fn columns(mut self, columns: impl IntoIterator<Item = ColumnSchema<'i, D>>) -> Self {
+        self.columns.extend(columns);
+        self
+    }
+
+    /// Adds a table-level `PRIMARY KEY` constraint over the given columns.
+    pub fn primary_key(mut self, columns: impl IntoIterator<Item = Column<'i>>) -> Self {
+        self.constraints
+            .push(TableConstraint::PrimaryKey(columns.into_iter().collect()));
+        self
+    }
+
+    /// Adds a table-level `FOREIGN KEY` constraint referencing another table.
+    pub fn foreign_key(
+        mut self,
+        columns: impl IntoIterator<Item = Column<'i>>,
+        references: SchemaTable<'i>,
+        references_columns: impl IntoIterator<Item = Column<'i>>,
+    ) -> Self {
+        self.constraints.push(TableConstraint::ForeignKey {
+            columns: columns.into_iter().collect(),
+            references,
+            references_columns: references_columns.into_iter().collect(),
+        });
+        self
+    }
+}
+
+impl<D: Dialect> fmt::Display for CreateTable<'_, D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "CREATE TABLE {} (", self.table.quoted_for::<D>())?;
+        let body = self
+            .columns
+            .iter()
+            .map(|column| column.to_string())
+            .chain(self.constraints.iter().map(|constraint| constraint.to_string_for::<D>()))
+            .map(|line| format!("    {}", line))
+            .join(",\n");
+        write!(f, "{}\n)", body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SqlServerDialect;
+
+    #[test]
+    fn build_create_table() {
+        let table = CreateTable::<'_, SqlServerDialect>::new(SchemaTable("foo".into(), "bar".into()))
+            .column(
+                ColumnSchema::for_type::<_, i32>("id")
+                    .primary_key()
+                    .auto_increment(),
+            )
+            .column(ColumnSchema::for_type::<_, Option<String>>("name"))
+            .primary_key(["id".into()]);
+
+        assert_eq!(
+            "CREATE TABLE foo.bar (\n    id INT NOT NULL PRIMARY KEY IDENTITY,\n    name NVARCHAR(4000) NULL,\n    PRIMARY KEY (id)\n)",
+            &table.to_string()
+        );
+    }
+}
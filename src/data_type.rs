@@ -1,13 +1,60 @@
+use std::fmt::{self, Write};
 use std::marker::PhantomData;
 use crate::ColumnType;
 
 /// SQL dialect of a database.
-pub trait Dialect: Clone + Copy {}
+pub trait Dialect: Clone + Copy {
+    /// Character that opens a quoted identifier in this dialect.
+    const QUOTE_OPEN: char;
+    /// Character that closes a quoted identifier in this dialect; doubled inside the
+    /// identifier to escape an occurrence of itself.
+    const QUOTE_CLOSE: char;
+
+    /// Writes the placeholder for the `index`-th (1-based) bound query parameter.
+    ///
+    /// Defaults to the positional `?` used by MySQL, SQL Server and MonetDB; dialects with
+    /// numbered placeholders, like Postgres' `$1`, `$2`, ..., override this.
+    fn write_placeholder(index: usize, sql: &mut String) {
+        let _ = index;
+        sql.push('?');
+    }
+
+    /// Column modifier keyword for auto-incrementing primary keys in this dialect.
+    const AUTO_INCREMENT: &'static str = "AUTO_INCREMENT";
+
+    /// Writes the dialect's row-limiting clause that appears immediately after `SELECT`, e.g.
+    /// SQL Server's `TOP n`. Most dialects don't have one and leave this empty. `offset` is
+    /// passed alongside `limit` so a dialect whose row-limiting clause can't combine with an
+    /// offset (e.g. SQL Server's `TOP`) can defer to `write_limit_offset` instead when one is set.
+    fn write_select_limit(limit: Option<u64>, offset: Option<u64>, _f: &mut fmt::Formatter) -> fmt::Result {
+        let _ = (limit, offset);
+        Ok(())
+    }
+
+    /// Writes the dialect's trailing `LIMIT`/`OFFSET` clause. Defaults to standard SQL
+    /// `LIMIT n OFFSET n`; dialects that render the limit via `write_select_limit` instead
+    /// (e.g. SQL Server's `TOP`) override this, writing nothing when `write_select_limit`
+    /// already rendered the limit.
+    fn write_limit_offset(limit: Option<u64>, offset: Option<u64>, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(limit) = limit {
+            write!(f, " LIMIT {}", limit)?;
+        }
+        if let Some(offset) = offset {
+            write!(f, " OFFSET {}", offset)?;
+        }
+        Ok(())
+    }
+}
 
 /// Provide SQL data types in given dialect corresponding to Rust types.
 pub trait SqlDataType<D: Dialect> {
     /// Gets `ColumnType` for given `Dialect` corresponding to `Self`.
     fn sql_type() -> ColumnType<D>;
+
+    /// Whether this Rust type maps to a nullable SQL column, e.g. `Option<T>`/`Nullable<T>`.
+    fn is_nullable() -> bool {
+        false
+    }
 }
 
 #[macro_export]
@@ -21,10 +68,71 @@ macro_rules! impl_sql_data_type {
     }
 }
 
+/// Marks a Rust type's corresponding SQL column type as nullable, representing nullability
+/// as a property of the SQL type itself rather than a separate trait, so `ColumnSchema` can
+/// derive the `NULL`/`NOT NULL` suffix from the type alone.
+pub struct Nullable<T>(PhantomData<T>);
+
+impl<D: Dialect, T: SqlDataType<D>> SqlDataType<D> for Nullable<T> {
+    fn sql_type() -> ColumnType<D> {
+        T::sql_type()
+    }
+
+    fn is_nullable() -> bool {
+        true
+    }
+}
+
+impl<D: Dialect, T: SqlDataType<D>> SqlDataType<D> for Option<T> {
+    fn sql_type() -> ColumnType<D> {
+        T::sql_type()
+    }
+
+    fn is_nullable() -> bool {
+        true
+    }
+}
+
+/// Dialect agnostic ANSI SQL identifier quoting; used as the default for objects that are
+/// not tied to any particular backend.
+#[derive(Clone, Copy, Debug)]
+pub struct AnsiDialect;
+impl Dialect for AnsiDialect {
+    const QUOTE_OPEN: char = '"';
+    const QUOTE_CLOSE: char = '"';
+}
+
 /// SQL Server SQL dialect.
 #[derive(Clone, Copy, Debug)]
 pub struct SqlServerDialect;
-impl Dialect for SqlServerDialect {}
+impl Dialect for SqlServerDialect {
+    const QUOTE_OPEN: char = '[';
+    const QUOTE_CLOSE: char = ']';
+    const AUTO_INCREMENT: &'static str = "IDENTITY";
+
+    /// Only renders as `TOP n` when there's no offset; an offset is incompatible with `TOP`
+    /// and is instead rendered by `write_limit_offset` as `OFFSET ... FETCH NEXT ...`.
+    fn write_select_limit(limit: Option<u64>, offset: Option<u64>, f: &mut fmt::Formatter) -> fmt::Result {
+        if offset.is_none() {
+            if let Some(limit) = limit {
+                write!(f, "TOP {} ", limit)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Renders `OFFSET n ROWS [FETCH NEXT m ROWS ONLY]` when an offset is set; a limit with no
+    /// offset is already handled by `write_select_limit`'s `TOP n`, so this is a no-op then.
+    fn write_limit_offset(limit: Option<u64>, offset: Option<u64>, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(offset) = offset {
+            write!(f, " OFFSET {} ROWS", offset)?;
+            if let Some(limit) = limit {
+                write!(f, " FETCH NEXT {} ROWS ONLY", limit)?;
+            }
+        }
+        Ok(())
+    }
+}
 
 impl_sql_data_type!(SqlServerDialect, bool, "BIT");
 impl_sql_data_type!(SqlServerDialect, i8, "TINYINT");
@@ -38,7 +146,11 @@ impl_sql_data_type!(SqlServerDialect, String, "NVARCHAR(4000)");
 /// MonetDB SQL dialect.
 #[derive(Clone, Copy, Debug)]
 pub struct MonetDbDialect;
-impl Dialect for MonetDbDialect {}
+impl Dialect for MonetDbDialect {
+    const QUOTE_OPEN: char = '"';
+    const QUOTE_CLOSE: char = '"';
+    const AUTO_INCREMENT: &'static str = "GENERATED ALWAYS AS IDENTITY";
+}
 
 impl_sql_data_type!(MonetDbDialect, bool, "BOOLEAN");
 impl_sql_data_type!(MonetDbDialect, i8, "TINYINT");
@@ -47,3 +159,53 @@ impl_sql_data_type!(MonetDbDialect, i32, "INT");
 impl_sql_data_type!(MonetDbDialect, i64, "BIGINT");
 impl_sql_data_type!(MonetDbDialect, f64, "DOUBLE");
 impl_sql_data_type!(MonetDbDialect, String, "STRING");
+
+/// Postgres SQL dialect.
+#[derive(Clone, Copy, Debug)]
+pub struct PostgresDialect;
+impl Dialect for PostgresDialect {
+    const QUOTE_OPEN: char = '"';
+    const QUOTE_CLOSE: char = '"';
+    const AUTO_INCREMENT: &'static str = "GENERATED ALWAYS AS IDENTITY";
+
+    fn write_placeholder(index: usize, sql: &mut String) {
+        write!(sql, "${}", index).expect("write to String cannot fail");
+    }
+}
+
+impl_sql_data_type!(PostgresDialect, bool, "BOOLEAN");
+impl_sql_data_type!(PostgresDialect, i16, "SMALLINT");
+impl_sql_data_type!(PostgresDialect, i32, "INTEGER");
+impl_sql_data_type!(PostgresDialect, i64, "BIGINT");
+impl_sql_data_type!(PostgresDialect, f32, "REAL");
+impl_sql_data_type!(PostgresDialect, f64, "DOUBLE PRECISION");
+impl_sql_data_type!(PostgresDialect, String, "TEXT");
+
+/// MySQL SQL dialect.
+#[derive(Clone, Copy, Debug)]
+pub struct MySqlDialect;
+impl Dialect for MySqlDialect {
+    const QUOTE_OPEN: char = '`';
+    const QUOTE_CLOSE: char = '`';
+
+    /// MySQL's grammar requires `LIMIT` whenever `OFFSET` is given; a bare `OFFSET` with no
+    /// `LIMIT` is a syntax error. When only an offset is set, writes the largest possible
+    /// `LIMIT` so the clause stays valid while leaving the result set otherwise unrestricted.
+    fn write_limit_offset(limit: Option<u64>, offset: Option<u64>, f: &mut fmt::Formatter) -> fmt::Result {
+        match (limit, offset) {
+            (Some(limit), Some(offset)) => write!(f, " LIMIT {} OFFSET {}", limit, offset),
+            (Some(limit), None) => write!(f, " LIMIT {}", limit),
+            (None, Some(offset)) => write!(f, " LIMIT {} OFFSET {}", u64::MAX, offset),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+impl_sql_data_type!(MySqlDialect, bool, "TINYINT(1)");
+impl_sql_data_type!(MySqlDialect, i8, "TINYINT");
+impl_sql_data_type!(MySqlDialect, i16, "SMALLINT");
+impl_sql_data_type!(MySqlDialect, i32, "INT");
+impl_sql_data_type!(MySqlDialect, i64, "BIGINT");
+impl_sql_data_type!(MySqlDialect, f32, "FLOAT");
+impl_sql_data_type!(MySqlDialect, f64, "DOUBLE");
+impl_sql_data_type!(MySqlDialect, String, "TEXT");
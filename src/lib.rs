@@ -30,35 +30,54 @@ All objects are using base escaping rules wrappers:
 * `QuotedDataConcat` for data values
 */
 use itertools::Itertools;
-use std::fmt::{self, Display};
+use std::fmt::{self, Display, Write};
 use std::marker::PhantomData;
 
 mod predicates;
 pub use predicates::*;
 mod data_type;
 pub use data_type::*;
-
-/// Concatenation of strings with object escaping rules.
+mod query_builder;
+pub use query_builder::*;
+mod create_table;
+pub use create_table::*;
+mod select;
+pub use select::*;
+
+/// Concatenation of strings with object escaping rules for a given SQL `Dialect`.
 ///
 /// Escaping rules:
-/// * as-is, if does not contain " or space
-/// * surround " and escape " with ""
+/// * as-is, if does not contain the dialect's quote characters or a space
+/// * surround in the dialect's quote characters and escape the closing one by doubling it,
+///   e.g. `"ident"` for Postgres/MonetDB, `` `ident` `` for MySQL, `[ident]` for SQL Server
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
-pub struct ObjectConcat<'i>(pub &'i [&'i str]);
+pub struct ObjectConcat<'i, D: Dialect = AnsiDialect>(pub &'i [&'i str], pub PhantomData<D>);
 
-impl fmt::Display for ObjectConcat<'_> {
+impl<D: Dialect> fmt::Display for ObjectConcat<'_, D> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         if self.0.iter().any(|o| o.contains("'") || o.contains("\\")) {
-            // MonetDB does not like ' or \ in column names
+            // No supported dialect allows ' or \ in identifiers
             return Err(fmt::Error);
         }
 
-        if self.0.iter().any(|o| o.contains(" ") || o.contains("\"")) {
-            f.write_str("\"")?;
-            for part in self.0.iter().flat_map(|o| o.split("\"").intersperse("\"\"")) {
+        if self
+            .0
+            .iter()
+            .any(|o| o.contains(" ") || o.contains(D::QUOTE_OPEN) || o.contains(D::QUOTE_CLOSE))
+        {
+            let mut doubled_close = String::with_capacity(2);
+            doubled_close.push(D::QUOTE_CLOSE);
+            doubled_close.push(D::QUOTE_CLOSE);
+
+            f.write_char(D::QUOTE_OPEN)?;
+            for part in self
+                .0
+                .iter()
+                .flat_map(|o| o.split(D::QUOTE_CLOSE).intersperse(doubled_close.as_str()))
+            {
                 f.write_str(part)?;
             }
-            f.write_str("\"")?;
+            f.write_char(D::QUOTE_CLOSE)?;
         } else {
             for o in self.0.iter() {
                 f.write_str(o)?;
@@ -70,17 +89,17 @@ impl fmt::Display for ObjectConcat<'_> {
 
 //TODO: reimplement using const generics when stable
 /// Owned variant of `ObjectConcat` to be returned as `impl Display`.
-pub struct ObjectConcatDisplay<'i>(Box<[&'i str]>);
+pub struct ObjectConcatDisplay<'i, D: Dialect = AnsiDialect>(Box<[&'i str]>, PhantomData<D>);
 
-impl<'i> ObjectConcatDisplay<'i> {
+impl<'i, D: Dialect> ObjectConcatDisplay<'i, D> {
     pub fn as_quoted_data(self) -> QuotedDataConcatDisplay<'i> {
-        self.into()
+        QuotedDataConcatDisplay(self.0)
     }
 }
 
-impl fmt::Display for ObjectConcatDisplay<'_> {
+impl<D: Dialect> fmt::Display for ObjectConcatDisplay<'_, D> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        ObjectConcat(&self.0).fmt(f)
+        ObjectConcat::<D>(&self.0, PhantomData).fmt(f)
     }
 }
 
@@ -135,6 +154,11 @@ impl<'i> Object<'i> {
     pub fn as_quoted_data(&self) -> QuotedDataConcatDisplay<'i> {
         QuotedDataConcatDisplay(Box::new([self.as_str()]))
     }
+
+    /// Gets object quoted according to the given SQL `Dialect`'s identifier quoting rules.
+    pub fn quoted_for<D: Dialect>(&self) -> ObjectConcatDisplay<'i, D> {
+        ObjectConcatDisplay(Box::new([self.as_str()]), PhantomData)
+    }
 }
 
 impl<'i> From<&'i str> for Object<'i> {
@@ -145,7 +169,7 @@ impl<'i> From<&'i str> for Object<'i> {
 
 impl fmt::Display for Object<'_> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        ObjectConcat(&[self.0]).fmt(f)
+        ObjectConcat::<AnsiDialect>(&[self.0], PhantomData).fmt(f)
     }
 }
 
@@ -206,6 +230,11 @@ impl<'i> Schema<'i> {
     pub fn as_quoted_data(&self) -> QuotedDataConcatDisplay<'i> {
         self.0.as_quoted_data()
     }
+
+    /// Gets object quoted according to the given SQL `Dialect`'s identifier quoting rules.
+    pub fn quoted_for<D: Dialect>(&self) -> ObjectConcatDisplay<'i, D> {
+        self.0.quoted_for::<D>()
+    }
 }
 
 impl<'i, O: Into<Object<'i>> > From<O> for Schema<'i> {
@@ -232,13 +261,13 @@ impl<'i> Table<'i> {
 
     /// Returns object implementing `Display` to format this table name with given postfix.
     pub fn with_postfix(&self, postfix: &'i str) -> ObjectConcatDisplay<'i> {
-        ObjectConcatDisplay(Box::new([self.as_str(), postfix]))
+        ObjectConcatDisplay(Box::new([self.as_str(), postfix]), PhantomData)
     }
 
     /// Returns object implementing `Display` to format this table name with given postfix
     /// separated with given separator.
     pub fn with_postfix_sep(&self, postfix: &'i str, separator: &'i str) -> ObjectConcatDisplay<'i> {
-        ObjectConcatDisplay(Box::new([self.as_str(), separator, postfix]))
+        ObjectConcatDisplay(Box::new([self.as_str(), separator, postfix]), PhantomData)
     }
 
     /// Gets original value.
@@ -250,6 +279,11 @@ impl<'i> Table<'i> {
     pub fn as_quoted_data(&self) -> QuotedDataConcatDisplay<'i> {
         self.0.as_quoted_data()
     }
+
+    /// Gets object quoted according to the given SQL `Dialect`'s identifier quoting rules.
+    pub fn quoted_for<D: Dialect>(&self) -> ObjectConcatDisplay<'i, D> {
+        self.0.quoted_for::<D>()
+    }
 }
 
 impl<'i, O: Into<Object<'i>> > From<O> for Table<'i> {
@@ -286,20 +320,25 @@ impl<'i> SchemaTable<'i> {
     /// Returns object implementing `Display` to format this table name with given postfix.
     pub fn with_postfix(&self, postfix: &'i str) -> impl Display + 'i {
         let a = self.as_array();
-        ObjectConcatDisplay(Box::new([a[0], a[1], a[2], postfix]))
+        ObjectConcatDisplay(Box::new([a[0], a[1], a[2], postfix]), PhantomData::<AnsiDialect>)
     }
 
     /// Returns object implementing `Display` to format this table name with given postfix
     /// separated with given separator.
     pub fn with_postfix_sep(&self, postfix: &'i str, separator: &'i str) -> ObjectConcatDisplay<'i> {
         let a = self.as_array();
-        ObjectConcatDisplay(Box::new([a[0], a[1], a[2], separator, postfix]))
+        ObjectConcatDisplay(Box::new([a[0], a[1], a[2], separator, postfix]), PhantomData)
     }
 
     /// Gets object represented as quoted data.
     pub fn as_quoted_data(&self) -> QuotedDataConcatDisplay<'i> {
         QuotedDataConcatDisplay(Box::new(self.as_array()))
     }
+
+    /// Gets object quoted according to the given SQL `Dialect`'s identifier quoting rules.
+    pub fn quoted_for<D: Dialect>(&self) -> ObjectConcatDisplay<'i, D> {
+        ObjectConcatDisplay(Box::new(self.as_array()), PhantomData)
+    }
 }
 
 impl<'i, S: Into<Schema<'i>>, T: Into<Table<'i>>> From<(S, T)> for SchemaTable<'i> {
@@ -310,7 +349,7 @@ impl<'i, S: Into<Schema<'i>>, T: Into<Table<'i>>> From<(S, T)> for SchemaTable<'
 
 impl fmt::Display for SchemaTable<'_> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        ObjectConcat(&self.as_array()).fmt(f)
+        ObjectConcat::<AnsiDialect>(&self.as_array(), PhantomData).fmt(f)
     }
 }
 
@@ -328,6 +367,11 @@ impl<'i> Column<'i> {
     pub fn as_quoted_data(&self) -> QuotedDataConcatDisplay<'i> {
         self.0.as_quoted_data()
     }
+
+    /// Gets object quoted according to the given SQL `Dialect`'s identifier quoting rules.
+    pub fn quoted_for<D: Dialect>(&self) -> ObjectConcatDisplay<'i, D> {
+        self.0.quoted_for::<D>()
+    }
 }
 
 impl<'i, O: Into<Object<'i>>> From<O> for Column<'i> {
@@ -365,31 +409,138 @@ impl<D: Dialect> fmt::Display for ColumnType<D> {
     }
 }
 
-/// Represents column name and type for given SQL `Dialect`.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
-pub struct ColumnSchema<'i, D: Dialect>(pub Column<'i>, pub ColumnType<D>);
+/// Column constraint rendered as part of a `CREATE TABLE` column definition.
+pub enum ColumnOption<'i> {
+    PrimaryKey,
+    Unique,
+    AutoIncrement,
+    Default(Box<dyn Display + 'i>),
+}
+
+impl fmt::Debug for ColumnOption<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ColumnOption::PrimaryKey => f.write_str("PrimaryKey"),
+            ColumnOption::Unique => f.write_str("Unique"),
+            ColumnOption::AutoIncrement => f.write_str("AutoIncrement"),
+            ColumnOption::Default(expr) => write!(f, "Default({})", expr),
+        }
+    }
+}
+
+impl PartialEq for ColumnOption<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (ColumnOption::PrimaryKey, ColumnOption::PrimaryKey) => true,
+            (ColumnOption::Unique, ColumnOption::Unique) => true,
+            (ColumnOption::AutoIncrement, ColumnOption::AutoIncrement) => true,
+            (ColumnOption::Default(a), ColumnOption::Default(b)) => a.to_string() == b.to_string(),
+            _ => false,
+        }
+    }
+}
+
+/// Represents column name, type and constraints for given SQL `Dialect`.
+///
+/// Holds boxed `DEFAULT` expressions, so unlike this crate's other new-types it cannot derive
+/// `Clone`/`Copy`/`Eq`/`Ord`; `Debug`/`PartialEq` are derived on top of `ColumnOption`'s
+/// hand-written impls, which format/compare boxed expressions through their rendered SQL text.
+#[derive(Debug, PartialEq)]
+pub struct ColumnSchema<'i, D: Dialect> {
+    column: Column<'i>,
+    column_type: ColumnType<D>,
+    nullable: Option<bool>,
+    options: Vec<ColumnOption<'i>>,
+}
 
 impl<'i, D: Dialect> ColumnSchema<'i, D> {
+    /// Creates a column schema for a Rust type implementing `SqlDataType`, deriving its
+    /// `NULL`/`NOT NULL` suffix from whether the type is nullable (e.g. `Option<i32>` or
+    /// `Nullable<i32>`).
+    pub fn for_type<C: Into<Column<'i>>, T: SqlDataType<D>>(column: C) -> Self {
+        ColumnSchema {
+            column: column.into(),
+            column_type: T::sql_type(),
+            nullable: Some(T::is_nullable()),
+            options: Vec::new(),
+        }
+    }
+
     /// Gets `Column` part
     pub fn column(&self) -> &Column<'i> {
-        &self.0
+        &self.column
     }
 
     /// Gets `ColumnType` part
     pub fn column_type(&self) -> &ColumnType<D> {
-        &self.1
+        &self.column_type
+    }
+
+    /// Marks the column as `NOT NULL`.
+    pub fn not_null(mut self) -> Self {
+        self.nullable = Some(false);
+        self
+    }
+
+    /// Marks the column as `NULL`.
+    pub fn null(mut self) -> Self {
+        self.nullable = Some(true);
+        self
+    }
+
+    /// Adds a `DEFAULT <expr>` clause.
+    pub fn default(mut self, expr: impl Display + 'i) -> Self {
+        self.options.push(ColumnOption::Default(Box::new(expr)));
+        self
+    }
+
+    /// Adds a `PRIMARY KEY` clause.
+    pub fn primary_key(mut self) -> Self {
+        self.options.push(ColumnOption::PrimaryKey);
+        self
+    }
+
+    /// Adds a `UNIQUE` clause.
+    pub fn unique(mut self) -> Self {
+        self.options.push(ColumnOption::Unique);
+        self
+    }
+
+    /// Adds the dialect's auto-increment clause (`AUTO_INCREMENT`/`IDENTITY`).
+    pub fn auto_increment(mut self) -> Self {
+        self.options.push(ColumnOption::AutoIncrement);
+        self
     }
 }
 
 impl<'i, D: Dialect, C: Into<Column<'i>>, T: Into<ColumnType<D>>> From<(C, T)> for ColumnSchema<'i, D> {
     fn from((name, r#type): (C, T)) -> ColumnSchema<'i, D> {
-        ColumnSchema(name.into(), r#type.into())
+        ColumnSchema {
+            column: name.into(),
+            column_type: r#type.into(),
+            nullable: None,
+            options: Vec::new(),
+        }
     }
 }
 
 impl<D: Dialect> fmt::Display for ColumnSchema<'_, D> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{} {}", self.0, self.1)
+        write!(f, "{} {}", self.column.quoted_for::<D>(), self.column_type)?;
+        match self.nullable {
+            Some(true) => f.write_str(" NULL")?,
+            Some(false) => f.write_str(" NOT NULL")?,
+            None => {}
+        }
+        for option in &self.options {
+            match option {
+                ColumnOption::PrimaryKey => f.write_str(" PRIMARY KEY")?,
+                ColumnOption::Unique => f.write_str(" UNIQUE")?,
+                ColumnOption::AutoIncrement => write!(f, " {}", D::AUTO_INCREMENT)?,
+                ColumnOption::Default(expr) => write!(f, " DEFAULT {}", expr)?,
+            }
+        }
+        Ok(())
     }
 }
 
@@ -417,7 +568,7 @@ mod tests {
             r#""hello ""world"" foo_""quix""""#,
             &format!(
                 "{}",
-                ObjectConcat(&[r#"hello "world" foo"#, r#"_"quix""#])
+                ObjectConcat::<AnsiDialect>(&[r#"hello "world" foo"#, r#"_"quix""#], PhantomData)
             )
         );
 
@@ -425,8 +576,49 @@ mod tests {
             "foo_bar_baz",
             &format!(
                 "{}",
-                ObjectConcat(&["foo_", "bar", "_baz"])
+                ObjectConcat::<AnsiDialect>(&["foo_", "bar", "_baz"], PhantomData)
             )
         );
     }
+
+    #[test]
+    fn build_object_concat_dialect_quoting() {
+        assert_eq!(
+            "[hello ]]world]] foo]",
+            &format!("{}", ObjectConcat::<SqlServerDialect>(&["hello ]world] foo"], PhantomData))
+        );
+
+        assert_eq!(
+            "`hello ``world`` foo`",
+            &format!("{}", ObjectConcat::<MySqlDialect>(&["hello `world` foo"], PhantomData))
+        );
+
+        assert_eq!(
+            "`foo bar`",
+            &Column::from("foo bar").quoted_for::<MySqlDialect>().to_string()
+        );
+    }
+
+    #[test]
+    fn build_column_schema() {
+        assert_eq!(
+            "id INT",
+            &ColumnSchema::<'_, SqlServerDialect>::from(("id", "INT")).to_string()
+        );
+
+        assert_eq!(
+            "id INT NOT NULL PRIMARY KEY IDENTITY",
+            &ColumnSchema::<'_, SqlServerDialect>::for_type::<_, i32>("id")
+                .primary_key()
+                .auto_increment()
+                .to_string()
+        );
+
+        assert_eq!(
+            "name NVARCHAR(4000) NULL DEFAULT 'unknown'",
+            &ColumnSchema::<'_, SqlServerDialect>::for_type::<_, Option<String>>("name")
+                .default(QuotedData("unknown"))
+                .to_string()
+        );
+    }
 }
@@ -1,39 +1,117 @@
-use itertools::Itertools;
+use std::any::{Any, TypeId};
 use std::fmt::{self, Display};
+use std::mem;
+
+/// Boolean predicate tree supporting nested `AND`/`OR` groups and negation.
+///
+/// `Leaf` holds an already formatted predicate fragment (e.g. `"foo = 'bar'"`). `And`/`Or`
+/// hold their child predicates and `Display` them joined by `AND`/`OR`, parenthesizing any
+/// child that is itself a multi-element group so precedence is preserved. A group with a
+/// single child flattens to that child without adding a redundant group of its own.
+pub enum Predicate {
+    And(Vec<Predicate>),
+    Or(Vec<Predicate>),
+    Not(Box<Predicate>),
+    Leaf(Box<dyn Display>),
+}
+
+/// Recovers `value` as a `Predicate` if that's what it concretely is, so callers can fold a
+/// pre-built predicate tree in by its actual `And`/`Or`/`Not` shape instead of boxing it up as
+/// an opaque `Display` leaf and losing that shape (and the grouping parens it needs).
+fn downcast_predicate<S: Display + 'static>(value: S) -> Result<Predicate, S> {
+    if TypeId::of::<S>() == TypeId::of::<Predicate>() {
+        let value: Box<dyn Any> = Box::new(value);
+        Ok(*value.downcast::<Predicate>().expect("TypeId equality checked above"))
+    } else {
+        Err(value)
+    }
+}
+
+impl Predicate {
+    /// Whether this predicate is a multi-element `And`/`Or` group that needs to be
+    /// parenthesized when nested as a child of another group.
+    fn is_group(&self) -> bool {
+        matches!(self, Predicate::And(v) | Predicate::Or(v) if v.len() > 1)
+    }
+
+    fn join(f: &mut fmt::Formatter<'_>, children: &[Predicate], separator: &str) -> fmt::Result {
+        match children {
+            [] => Ok(()),
+            [single] => single.fmt(f),
+            [first, rest @ ..] => {
+                AsChild(first).fmt(f)?;
+                for child in rest {
+                    f.write_str(separator)?;
+                    AsChild(child).fmt(f)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl fmt::Display for Predicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Predicate::Leaf(predicate) => predicate.fmt(f),
+            Predicate::Not(predicate) => write!(f, "NOT ({})", predicate),
+            Predicate::And(children) => Predicate::join(f, children, "\nAND "),
+            Predicate::Or(children) => Predicate::join(f, children, "\nOR "),
+        }
+    }
+}
+
+/// Wraps a predicate in `(...)` when it is a group, so it stays unambiguous as a child of
+/// another group.
+struct AsChild<'p>(&'p Predicate);
+
+impl fmt::Display for AsChild<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_group() {
+            write!(f, "({})", self.0)
+        } else {
+            self.0.fmt(f)
+        }
+    }
+}
 
 /// SQL statment with boolean logic.
 pub struct PredicateStatement<'s> {
     statement: &'static str,
-    predicates: &'s [Box<dyn Display>],
+    predicate: &'s Predicate,
 }
 
 impl fmt::Display for PredicateStatement<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{} {}",
-            self.statement,
-            self.predicates.iter().join("\nAND ")
-        )
+        write!(f, "{} {}", self.statement, self.predicate)
     }
 }
 
-/// Collection of boolean predicates.
-pub struct Predicates(Vec<Box<dyn Display>>);
+/// Collection of boolean predicates, built up as a tree and rendered as a flat `AND` chain
+/// unless `.or()`/`.or_all()`/`.not()` introduce other groups.
+pub struct Predicates(Predicate);
 
 impl IntoIterator for Predicates {
-    type Item = Box<dyn Display>;
-    type IntoIter = std::vec::IntoIter<Box<dyn Display>>;
+    type Item = Predicate;
+    type IntoIter = std::vec::IntoIter<Predicate>;
 
+    /// Yields the whole accumulated predicate as a single item, preserving any `AND`/`OR`
+    /// grouping it holds, or no items at all if nothing was ever added. Used by `and_all`/
+    /// `or_all` to fold a pre-built `Predicates` in by its shape: a group of the same operator
+    /// is spliced in flat, any other group is kept as one parenthesized child.
     fn into_iter(self) -> Self::IntoIter {
-        self.0.into_iter()
+        if Predicates::is_empty(&self.0) {
+            Vec::new().into_iter()
+        } else {
+            vec![self.0].into_iter()
+        }
     }
 }
 
 impl Predicates {
     /// Create empty collection.
     pub fn new() -> Predicates {
-        Predicates(Vec::new())
+        Predicates(Predicate::And(Vec::new()))
     }
 
     /// Creates collection containing given predicate.
@@ -52,30 +130,113 @@ impl Predicates {
     }
 
     /// Gets WHERE statement with predicates.
-    pub fn as_where<'s>(&'s self) -> PredicateStatement<'s> {
+    pub fn as_where(&self) -> PredicateStatement<'_> {
         PredicateStatement {
             statement: "WHERE",
-            predicates: &self.0,
+            predicate: &self.0,
         }
     }
 
-    /// Appends predicate.
+    /// Gets HAVING statement with predicates.
+    pub fn as_having(&self) -> PredicateStatement<'_> {
+        PredicateStatement {
+            statement: "HAVING",
+            predicate: &self.0,
+        }
+    }
+
+    /// Gets ON statement with predicates, for use in a `JOIN ... ON` clause.
+    pub fn as_on(&self) -> PredicateStatement<'_> {
+        PredicateStatement {
+            statement: "ON",
+            predicate: &self.0,
+        }
+    }
+
+    /// Whether the accumulated predicate is still the empty root, i.e. nothing was added yet.
+    fn is_empty(predicate: &Predicate) -> bool {
+        matches!(predicate, Predicate::And(v) if v.is_empty())
+    }
+
+    /// Merges `other` into the accumulated predicate by `AND`, flattening into the existing
+    /// `And` group where possible.
+    fn and_merge(&mut self, other: Predicate) {
+        let current = mem::replace(&mut self.0, Predicate::And(Vec::new()));
+        self.0 = match current {
+            current if Self::is_empty(&current) => other,
+            Predicate::And(mut children) => {
+                children.push(other);
+                Predicate::And(children)
+            }
+            current => Predicate::And(vec![current, other]),
+        };
+    }
+
+    /// Merges `other` into the accumulated predicate by `OR`, flattening into the existing
+    /// `Or` group where possible.
+    fn or_merge(&mut self, other: Predicate) {
+        let current = mem::replace(&mut self.0, Predicate::And(Vec::new()));
+        self.0 = match current {
+            current if Self::is_empty(&current) => other,
+            Predicate::Or(mut children) => {
+                children.push(other);
+                Predicate::Or(children)
+            }
+            current => Predicate::Or(vec![current, other]),
+        };
+    }
+
+    /// Merges `other` in by `AND`, splicing its own children in flat if it is itself an `And`
+    /// group (associative, so no grouping is lost), or merging it in whole as one child
+    /// otherwise, so an `Or` group (or any other shape) keeps the parens it needs.
+    fn and_merge_flatten(&mut self, other: Predicate) {
+        match other {
+            Predicate::And(children) => {
+                for child in children {
+                    self.and_merge(child);
+                }
+            }
+            other => self.and_merge(other),
+        }
+    }
+
+    /// Merges `other` in by `OR`, splicing its own children in flat if it is itself an `Or`
+    /// group (associative, so no grouping is lost), or merging it in whole as one child
+    /// otherwise, so an `And` group (or any other shape) keeps the parens it needs.
+    fn or_merge_flatten(&mut self, other: Predicate) {
+        match other {
+            Predicate::Or(children) => {
+                for child in children {
+                    self.or_merge(child);
+                }
+            }
+            other => self.or_merge(other),
+        }
+    }
+
+    /// Appends predicate with AND.
+    ///
+    /// If `predicate` is itself a whole `Predicate` (as yielded by iterating a pre-built
+    /// `Predicates`, e.g. via `and_all`), it is folded in by `and_merge_flatten` instead of
+    /// being boxed up as an opaque leaf fragment, so that a nested `Or`/`Not` group keeps its
+    /// parens and a nested `And` group still flattens into a single flat chain.
     pub fn and_push<S: Display + 'static>(&mut self, predicate: S) {
-        self.and_extend(Some(predicate))
+        match downcast_predicate(predicate) {
+            Ok(predicate) => self.and_merge_flatten(predicate),
+            Err(predicate) => self.and_merge(Predicate::Leaf(Box::new(predicate))),
+        }
     }
 
-    /// Appends all predicates.
-    pub fn and_extend<S, I, IT>(&mut self, predicates: I) -> ()
+    /// Appends all predicates with AND.
+    pub fn and_extend<S, I, IT>(&mut self, predicates: I)
     where
         S: Display + 'static,
         I: IntoIterator<Item = S, IntoIter = IT>,
         IT: Iterator<Item = S>,
     {
-        self.0.extend(
-            predicates
-                .into_iter()
-                .map(|c| Box::new(c) as Box<dyn Display>),
-        );
+        for predicate in predicates {
+            self.and_push(predicate);
+        }
     }
 
     /// Appends predicate with fluid API.
@@ -94,6 +255,59 @@ impl Predicates {
         self.and_extend(predicates);
         self
     }
+
+    /// Appends predicate with OR.
+    ///
+    /// If `predicate` is itself a whole `Predicate` (as yielded by iterating a pre-built
+    /// `Predicates`, e.g. via `or_all`), it is folded in by `or_merge_flatten` instead of being
+    /// boxed up as an opaque leaf fragment, so that a nested `And`/`Not` group keeps its parens
+    /// and a nested `Or` group still flattens into a single flat chain.
+    pub fn or_push<S: Display + 'static>(&mut self, predicate: S) {
+        match downcast_predicate(predicate) {
+            Ok(predicate) => self.or_merge_flatten(predicate),
+            Err(predicate) => self.or_merge(Predicate::Leaf(Box::new(predicate))),
+        }
+    }
+
+    /// Appends all predicates with OR.
+    pub fn or_extend<S, I, IT>(&mut self, predicates: I)
+    where
+        S: Display + 'static,
+        I: IntoIterator<Item = S, IntoIter = IT>,
+        IT: Iterator<Item = S>,
+    {
+        for predicate in predicates {
+            self.or_push(predicate);
+        }
+    }
+
+    /// Appends predicate with OR with fluid API.
+    pub fn or<S: Display + 'static>(mut self, predicate: S) -> Self {
+        self.or_push(predicate);
+        self
+    }
+
+    /// Appends all predicates with OR with fluid API.
+    pub fn or_all<S, I, IT>(mut self, predicates: I) -> Self
+    where
+        S: Display + 'static,
+        I: IntoIterator<Item = S, IntoIter = IT>,
+        IT: Iterator<Item = S>,
+    {
+        self.or_extend(predicates);
+        self
+    }
+
+    /// Appends negated predicate with AND.
+    pub fn not_push<S: Display + 'static>(&mut self, predicate: S) {
+        self.and_merge(Predicate::Not(Box::new(Predicate::Leaf(Box::new(predicate)))));
+    }
+
+    /// Appends negated predicate with AND with fluid API.
+    pub fn not<S: Display + 'static>(mut self, predicate: S) -> Self {
+        self.not_push(predicate);
+        self
+    }
 }
 
 #[cfg(test)]
@@ -112,4 +326,46 @@ mod tests {
             "WHERE foo = \'bar\'\nAND baz\nAND hello\nAND world\nAND abc\nAND 123"
         );
     }
+
+    #[test]
+    fn or_groups_and_negation() {
+        assert_eq!(
+            Predicates::new()
+                .and("a = 1")
+                .and("b = 2")
+                .or_all(Predicates::new().not("c = 3"))
+                .as_where()
+                .to_string(),
+            "WHERE (a = 1\nAND b = 2)\nOR NOT (c = 3)"
+        );
+    }
+
+    #[test]
+    fn single_element_groups_flatten() {
+        assert_eq!(
+            Predicates::new().or("only").as_where().to_string(),
+            "WHERE only"
+        );
+    }
+
+    #[test]
+    fn mismatched_groups_keep_their_parens_through_all() {
+        assert_eq!(
+            Predicates::new()
+                .and("a = 1")
+                .or_all(Predicates::new().and("x = 1").and("y = 2"))
+                .as_where()
+                .to_string(),
+            "WHERE a = 1\nOR (x = 1\nAND y = 2)"
+        );
+
+        assert_eq!(
+            Predicates::new()
+                .and("a = 1")
+                .and_all(Predicates::new().or("x = 1").or("y = 2"))
+                .as_where()
+                .to_string(),
+            "WHERE a = 1\nAND (x = 1\nOR y = 2)"
+        );
+    }
 }
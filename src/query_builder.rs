@@ -0,0 +1,140 @@
+use std::fmt::{self, Display, Write};
+use std::marker::PhantomData;
+
+use crate::Dialect;
+
+/// A value bound as a query parameter, covering the Rust types mapped in `data_type.rs`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    F32(f32),
+    F64(f64),
+    String(String),
+}
+
+macro_rules! impl_value_from {
+    ($t:ty, $variant:ident) => {
+        impl From<$t> for Value {
+            fn from(value: $t) -> Value {
+                Value::$variant(value)
+            }
+        }
+    }
+}
+
+impl_value_from!(bool, Bool);
+impl_value_from!(i8, I8);
+impl_value_from!(i16, I16);
+impl_value_from!(i32, I32);
+impl_value_from!(i64, I64);
+impl_value_from!(f32, F32);
+impl_value_from!(f64, F64);
+impl_value_from!(String, String);
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Value {
+        Value::String(value.into())
+    }
+}
+
+impl<T: Into<Value>> From<Option<T>> for Value {
+    fn from(value: Option<T>) -> Value {
+        match value {
+            Some(value) => value.into(),
+            None => Value::Null,
+        }
+    }
+}
+
+/// Accumulates SQL text and an ordered `Value` vector for drivers that take bound
+/// parameters, writing each dialect's own placeholder syntax into the text as values are
+/// bound instead of inlining escaped literals.
+pub struct QueryBuilder<D: Dialect> {
+    sql: String,
+    values: Vec<Value>,
+    dialect: PhantomData<D>,
+}
+
+impl<D: Dialect> QueryBuilder<D> {
+    /// Create empty query builder.
+    pub fn new() -> Self {
+        QueryBuilder {
+            sql: String::new(),
+            values: Vec::new(),
+            dialect: PhantomData,
+        }
+    }
+
+    /// Appends SQL text verbatim.
+    pub fn push(&mut self, fragment: impl Display) -> &mut Self {
+        write!(self.sql, "{}", fragment).expect("write to String cannot fail");
+        self
+    }
+
+    /// Binds a value, pushing it onto the parameter vector and writing the dialect's
+    /// placeholder for it into the SQL text.
+    pub fn bind(&mut self, value: impl Into<Value>) -> &mut Self {
+        self.values.push(value.into());
+        D::write_placeholder(self.values.len(), &mut self.sql);
+        self
+    }
+
+    /// Consumes the builder, returning the accumulated SQL text and bound parameter values.
+    pub fn build(self) -> (String, Vec<Value>) {
+        (self.sql, self.values)
+    }
+}
+
+impl<D: Dialect> Default for QueryBuilder<D> {
+    fn default() -> Self {
+        QueryBuilder::new()
+    }
+}
+
+impl<D: Dialect> fmt::Debug for QueryBuilder<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("QueryBuilder")
+            .field("sql", &self.sql)
+            .field("values", &self.values)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MySqlDialect, PostgresDialect};
+
+    #[test]
+    fn binds_positional_placeholders() {
+        let mut builder = QueryBuilder::<MySqlDialect>::new();
+        builder
+            .push("SELECT * FROM foo WHERE bar = ")
+            .bind(1i32)
+            .push(" AND baz = ")
+            .bind("quix");
+
+        let (sql, values) = builder.build();
+        assert_eq!(sql, "SELECT * FROM foo WHERE bar = ? AND baz = ?");
+        assert_eq!(values, vec![Value::I32(1), Value::String("quix".into())]);
+    }
+
+    #[test]
+    fn binds_numbered_placeholders() {
+        let mut builder = QueryBuilder::<PostgresDialect>::new();
+        builder
+            .push("SELECT * FROM foo WHERE bar = ")
+            .bind(1i32)
+            .push(" AND baz = ")
+            .bind(Option::<String>::None);
+
+        let (sql, values) = builder.build();
+        assert_eq!(sql, "SELECT * FROM foo WHERE bar = $1 AND baz = $2");
+        assert_eq!(values, vec![Value::I32(1), Value::Null]);
+    }
+}
@@ -0,0 +1,268 @@
+use itertools::Itertools;
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::{Column, Dialect, Predicates, SchemaTable};
+
+/// Join operator for a `Select`'s `JOIN` clause.
+pub enum JoinOperator {
+    Inner,
+    Left,
+    Right,
+}
+
+impl fmt::Display for JoinOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            JoinOperator::Inner => "INNER JOIN",
+            JoinOperator::Left => "LEFT JOIN",
+            JoinOperator::Right => "RIGHT JOIN",
+        })
+    }
+}
+
+/// A single `JOIN` clause with its own `ON` predicates.
+pub struct Join<'i> {
+    operator: JoinOperator,
+    table: SchemaTable<'i>,
+    on: Predicates,
+}
+
+impl Join<'_> {
+    fn to_string_for<D: Dialect>(&self) -> String {
+        format!("{} {} {}", self.operator, self.table.quoted_for::<D>(), self.on.as_on())
+    }
+}
+
+/// Sort direction of an `ORDER BY` expression.
+pub enum OrderDirection {
+    Asc,
+    Desc,
+}
+
+impl fmt::Display for OrderDirection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            OrderDirection::Asc => "ASC",
+            OrderDirection::Desc => "DESC",
+        })
+    }
+}
+
+struct OrderByExpr<'i> {
+    column: Column<'i>,
+    direction: OrderDirection,
+}
+
+impl OrderByExpr<'_> {
+    fn to_string_for<D: Dialect>(&self) -> String {
+        format!("{} {}", self.column.quoted_for::<D>(), self.direction)
+    }
+}
+
+enum Projection<'i> {
+    All,
+    Columns(Vec<Column<'i>>),
+}
+
+impl Projection<'_> {
+    fn to_string_for<D: Dialect>(&self) -> String {
+        match self {
+            Projection::All => "*".to_string(),
+            Projection::Columns(columns) => columns.iter().map(|c| c.quoted_for::<D>().to_string()).join(", "),
+        }
+    }
+}
+
+/// Builds a complete `SELECT` statement out of the crate's escaped fragments, for a given SQL
+/// `Dialect`.
+pub struct Select<'i, D: Dialect> {
+    projection: Projection<'i>,
+    from: SchemaTable<'i>,
+    joins: Vec<Join<'i>>,
+    r#where: Option<Predicates>,
+    group_by: Vec<Column<'i>>,
+    having: Option<Predicates>,
+    order_by: Vec<OrderByExpr<'i>>,
+    limit: Option<u64>,
+    offset: Option<u64>,
+    dialect: PhantomData<D>,
+}
+
+impl<'i, D: Dialect> Select<'i, D> {
+    /// Creates a `SELECT *` builder over the given table.
+    pub fn new(from: SchemaTable<'i>) -> Self {
+        Select {
+            projection: Projection::All,
+            from,
+            joins: Vec::new(),
+            r#where: None,
+            group_by: Vec::new(),
+            having: None,
+            order_by: Vec::new(),
+            limit: None,
+            offset: None,
+            dialect: PhantomData,
+        }
+    }
+
+    /// Restricts the projection to the given columns instead of `*`.
+    pub fn columns(mut self, columns: impl IntoIterator<Item = Column<'i>>) -> Self {
+        self.projection = Projection::Columns(columns.into_iter().collect());
+        self
+    }
+
+    /// Adds an `INNER JOIN` with the given `ON` predicates.
+    pub fn inner_join(mut self, table: SchemaTable<'i>, on: Predicates) -> Self {
+        self.joins.push(Join { operator: JoinOperator::Inner, table, on });
+        self
+    }
+
+    /// Adds a `LEFT JOIN` with the given `ON` predicates.
+    pub fn left_join(mut self, table: SchemaTable<'i>, on: Predicates) -> Self {
+        self.joins.push(Join { operator: JoinOperator::Left, table, on });
+        self
+    }
+
+    /// Adds a `RIGHT JOIN` with the given `ON` predicates.
+    pub fn right_join(mut self, table: SchemaTable<'i>, on: Predicates) -> Self {
+        self.joins.push(Join { operator: JoinOperator::Right, table, on });
+        self
+    }
+
+    /// Sets the `WHERE` predicates.
+    pub fn r#where(mut self, predicates: Predicates) -> Self {
+        self.r#where = Some(predicates);
+        self
+    }
+
+    /// Sets the `GROUP BY` columns.
+    pub fn group_by(mut self, columns: impl IntoIterator<Item = Column<'i>>) -> Self {
+        self.group_by = columns.into_iter().collect();
+        self
+    }
+
+    /// Sets the `HAVING` predicates.
+    pub fn having(mut self, predicates: Predicates) -> Self {
+        self.having = Some(predicates);
+        self
+    }
+
+    /// Appends an `ORDER BY` expression.
+    pub fn order_by(mut self, column: Column<'i>, direction: OrderDirection) -> Self {
+        self.order_by.push(OrderByExpr { column, direction });
+        self
+    }
+
+    /// Sets the `LIMIT` row count (on SQL Server `TOP`, or `FETCH NEXT` when an offset is set).
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Sets the `OFFSET` row count.
+    pub fn offset(mut self, offset: u64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+impl<D: Dialect> fmt::Display for Select<'_, D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("SELECT ")?;
+        D::write_select_limit(self.limit, self.offset, f)?;
+        write!(f, "{} FROM {}", self.projection.to_string_for::<D>(), self.from.quoted_for::<D>())?;
+
+        for join in &self.joins {
+            write!(f, " {}", join.to_string_for::<D>())?;
+        }
+
+        if let Some(r#where) = &self.r#where {
+            write!(f, " {}", r#where.as_where())?;
+        }
+
+        if !self.group_by.is_empty() {
+            write!(
+                f,
+                " GROUP BY {}",
+                self.group_by.iter().map(|c| c.quoted_for::<D>().to_string()).join(", ")
+            )?;
+        }
+
+        if let Some(having) = &self.having {
+            write!(f, " {}", having.as_having())?;
+        }
+
+        if !self.order_by.is_empty() {
+            write!(
+                f,
+                " ORDER BY {}",
+                self.order_by.iter().map(|o| o.to_string_for::<D>()).join(", ")
+            )?;
+        }
+
+        D::write_limit_offset(self.limit, self.offset, f)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MySqlDialect, SqlServerDialect};
+
+    #[test]
+    fn build_select() {
+        let select = Select::<MySqlDialect>::new(SchemaTable("foo".into(), "bar".into()))
+            .columns([Column::from("id"), Column::from("name")])
+            .inner_join(
+                SchemaTable("foo".into(), "baz".into()),
+                Predicates::from("bar.baz_id = baz.id"),
+            )
+            .r#where(Predicates::from("bar.active = 1"))
+            .group_by([Column::from("name")])
+            .having(Predicates::from("COUNT(*) > 1"))
+            .order_by(Column::from("name"), OrderDirection::Desc)
+            .limit(10)
+            .offset(20);
+
+        assert_eq!(
+            "SELECT id, name FROM foo.bar INNER JOIN foo.baz ON bar.baz_id = baz.id WHERE bar.active = 1 GROUP BY name HAVING COUNT(*) > 1 ORDER BY name DESC LIMIT 10 OFFSET 20",
+            &select.to_string()
+        );
+    }
+
+    #[test]
+    fn sql_server_uses_top_instead_of_limit() {
+        let select = Select::<SqlServerDialect>::new(SchemaTable("foo".into(), "bar".into())).limit(10);
+
+        assert_eq!("SELECT TOP 10 * FROM foo.bar", &select.to_string());
+    }
+
+    #[test]
+    fn mysql_offset_without_limit_gets_a_limit() {
+        let select = Select::<MySqlDialect>::new(SchemaTable("foo".into(), "bar".into())).offset(20);
+
+        assert_eq!(
+            format!("SELECT * FROM foo.bar LIMIT {} OFFSET 20", u64::MAX),
+            select.to_string()
+        );
+    }
+
+    #[test]
+    fn sql_server_offset_uses_offset_fetch_instead_of_top() {
+        let select = Select::<SqlServerDialect>::new(SchemaTable("foo".into(), "bar".into())).offset(20);
+
+        assert_eq!("SELECT * FROM foo.bar OFFSET 20 ROWS", &select.to_string());
+
+        let select = Select::<SqlServerDialect>::new(SchemaTable("foo".into(), "bar".into()))
+            .limit(10)
+            .offset(20);
+
+        assert_eq!(
+            "SELECT * FROM foo.bar OFFSET 20 ROWS FETCH NEXT 10 ROWS ONLY",
+            &select.to_string()
+        );
+    }
+}